@@ -1,51 +1,754 @@
+use std::collections::{HashMap, HashSet};
+
+use parquet2::encoding::hybrid_rle::encode_u32;
 use parquet2::encoding::Encoding;
-use parquet2::page::Page;
-use parquet2::schema::types::PrimitiveType;
+use parquet2::page::{DictPage, Page};
+use parquet2::schema::types::{PhysicalType, PrimitiveType};
+use parquet2::statistics::{
+    serialize_statistics, FixedLenStatistics, ParquetStatistics, PrimitiveStatistics,
+};
 
-use crate::parquet_write::file::WriteOptions;
+use crate::parquet_write::file::{StatisticsOptions, WriteOptions};
 use crate::parquet_write::ParquetResult;
-use crate::parquet_write::util::{build_plain_page, encode_bool_iter};
+use crate::parquet_write::util::{build_plain_page, build_plain_page_nested, encode_bool_iter};
+
+fn encode_plain<const N: usize>(data: &[[u8; N]], validity: Option<&[bool]>, buffer: &mut Vec<u8>) {
+    // append the non-null values only; null slots are dropped so that only
+    // defined values land in the data buffer, matching the definition levels.
+    // Indexed the same way the definition-level walk in bytes_to_page is, so a
+    // validity slice whose length disagrees with `data` fails loudly here
+    // instead of silently dropping trailing values.
+    match validity {
+        Some(validity) => {
+            debug_assert_eq!(validity.len(), data.len());
+            data.iter().enumerate().for_each(|(i, x)| {
+                if validity[i] {
+                    buffer.extend_from_slice(x);
+                }
+            })
+        }
+        None => data.iter().for_each(|x| buffer.extend_from_slice(x)),
+    }
+}
+
+fn build_statistics<const N: usize>(
+    data: &[[u8; N]],
+    validity: Option<&[bool]>,
+    null_count: usize,
+    options: StatisticsOptions,
+) -> ParquetStatistics {
+    let non_null_values = || {
+        data.iter().enumerate().filter_map(|(i, x)| match validity {
+            Some(validity) if !validity[i] => None,
+            _ => Some(x),
+        })
+    };
+
+    let mut min_value: Option<&[u8; N]> = None;
+    let mut max_value: Option<&[u8; N]> = None;
+    if options.min || options.max {
+        for x in non_null_values() {
+            if options.min {
+                min_value = Some(match min_value {
+                    Some(min) if min <= x => min,
+                    _ => x,
+                });
+            }
+            if options.max {
+                max_value = Some(match max_value {
+                    Some(max) if max >= x => max,
+                    _ => x,
+                });
+            }
+        }
+    }
+
+    let distinct_count = options.distinct_count.then(|| {
+        non_null_values().collect::<HashSet<_>>().len() as i64
+    });
+
+    let statistics = &FixedLenStatistics {
+        null_count: options.null_count.then_some(null_count as i64),
+        distinct_count,
+        min_value: min_value.map(|v| v.to_vec()),
+        max_value: max_value.map(|v| v.to_vec()),
+    };
+    serialize_statistics(statistics)
+}
+
+/// Like `build_statistics`, but over the decoded `i64` values rather than
+/// their raw little-endian bytes: an INT64 page needs integer-ordered
+/// min/max, since comparing the bytes lexicographically puts negative
+/// values (high bit set) above positive ones.
+fn build_i64_statistics(
+    values: &[i64],
+    null_count: usize,
+    options: StatisticsOptions,
+    primitive_type: PrimitiveType,
+) -> ParquetStatistics {
+    let mut min_value: Option<i64> = None;
+    let mut max_value: Option<i64> = None;
+    if options.min || options.max {
+        for &v in values {
+            if options.min {
+                min_value = Some(min_value.map_or(v, |min| min.min(v)));
+            }
+            if options.max {
+                max_value = Some(max_value.map_or(v, |max| max.max(v)));
+            }
+        }
+    }
+
+    let distinct_count = options
+        .distinct_count
+        .then(|| values.iter().copied().collect::<HashSet<_>>().len() as i64);
+
+    let statistics = &PrimitiveStatistics::<i64> {
+        primitive_type,
+        null_count: options.null_count.then_some(null_count as i64),
+        distinct_count,
+        min_value,
+        max_value,
+    };
+    serialize_statistics(statistics)
+}
+
+/// Dispatches to the dictionary-encoded path when `options.dictionary_encoding`
+/// is enabled, otherwise falls back to a single `Encoding::Plain` page.
+pub fn bytes_to_pages<const N: usize>(
+    data: &[[u8; N]],
+    validity: Option<&[bool]>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+) -> ParquetResult<Vec<Page>> {
+    if options.dictionary_encoding {
+        bytes_to_dict_pages(data, validity, options, type_)
+    } else {
+        bytes_to_page(data, validity, options, type_).map(|page| vec![page])
+    }
+}
+
+/// Entry point for 8-byte (Long-backed) fixed-size columns: picks
+/// DELTA_BINARY_PACKED when `options.delta_binary_packed_encoding` is set,
+/// otherwise defers to `bytes_to_pages` for the plain/dictionary choice.
+/// Mirrors `bytes_to_pages`'s dispatch so the delta path is reachable the
+/// same way the dictionary path is, rather than sitting as an orphan `pub fn`.
+pub fn i64_bytes_to_pages(
+    data: &[[u8; 8]],
+    validity: Option<&[bool]>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+) -> ParquetResult<Vec<Page>> {
+    if options.delta_binary_packed_encoding {
+        i64_bytes_to_delta_page(data, validity, options, type_).map(|page| vec![page])
+    } else {
+        bytes_to_pages(data, validity, options, type_)
+    }
+}
+
+const DELTA_BLOCK_SIZE: usize = 128;
+const DELTA_MINIBLOCKS_PER_BLOCK: usize = 4;
+const DELTA_VALUES_PER_MINIBLOCK: usize = DELTA_BLOCK_SIZE / DELTA_MINIBLOCKS_PER_BLOCK;
+
+fn zigzag_encode(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+fn write_uleb128(buffer: &mut Vec<u8>, mut v: u64) {
+    loop {
+        let byte = (v & 0x7F) as u8;
+        v >>= 7;
+        if v == 0 {
+            buffer.push(byte);
+            return;
+        }
+        buffer.push(byte | 0x80);
+    }
+}
+
+/// Bit-packs `values` (each already known to fit in `width` bits) into
+/// `buffer`, LSB-first, padding the final byte with zero bits. `width` is
+/// capped at 64 (a residual never needs more bits than a `u64`); the
+/// accumulator is widened to `u128` so that `width` plus the up-to-7
+/// leftover bits from the previous value can never overflow the shift.
+fn bitpack(buffer: &mut Vec<u8>, values: &[u64], width: u32) {
+    if width == 0 {
+        return;
+    }
+    debug_assert!(width <= 64);
+    let mut acc: u128 = 0;
+    let mut acc_bits = 0u32;
+    for &v in values {
+        acc |= (v as u128) << acc_bits;
+        acc_bits += width;
+        while acc_bits >= 8 {
+            buffer.push((acc & 0xFF) as u8);
+            acc >>= 8;
+            acc_bits -= 8;
+        }
+    }
+    if acc_bits > 0 {
+        buffer.push((acc & 0xFF) as u8);
+    }
+}
+
+/// Encodes `values` per the Parquet DELTA_BINARY_PACKED layout: a header
+/// (block size, miniblocks per block, total value count, first value as a
+/// zig-zag varint) followed by, per block, the minimum delta and the
+/// per-miniblock bit widths needed to bit-pack `delta - min_delta`.
+fn delta_binary_packed_encode(values: &[i64], buffer: &mut Vec<u8>) {
+    write_uleb128(buffer, DELTA_BLOCK_SIZE as u64);
+    write_uleb128(buffer, DELTA_MINIBLOCKS_PER_BLOCK as u64);
+    write_uleb128(buffer, values.len() as u64);
+    write_uleb128(buffer, zigzag_encode(values.first().copied().unwrap_or(0)));
+    if values.len() < 2 {
+        return;
+    }
+
+    // Parquet's delta encoding is defined over wrapping two's-complement
+    // arithmetic, so a pair of valid i64 values far apart (e.g. i64::MAX then
+    // i64::MIN) must not panic on overflow in debug builds.
+    let deltas: Vec<i64> = values.windows(2).map(|w| w[1].wrapping_sub(w[0])).collect();
+
+    for block in deltas.chunks(DELTA_BLOCK_SIZE) {
+        let min_delta = block.iter().copied().min().unwrap();
+        let mut residuals: Vec<u64> = block
+            .iter()
+            .map(|&d| d.wrapping_sub(min_delta) as u64)
+            .collect();
+        // A reader always consumes a full miniblock's worth of bit-packed
+        // values, so pad a short trailing block/miniblock with zero residuals
+        // rather than leaving it shorter than DELTA_VALUES_PER_MINIBLOCK.
+        residuals.resize(DELTA_MINIBLOCKS_PER_BLOCK * DELTA_VALUES_PER_MINIBLOCK, 0);
+        write_uleb128(buffer, zigzag_encode(min_delta));
+
+        let mut bit_widths = [0u8; DELTA_MINIBLOCKS_PER_BLOCK];
+        for (k, miniblock) in residuals.chunks(DELTA_VALUES_PER_MINIBLOCK).enumerate() {
+            let max = miniblock.iter().copied().max().unwrap_or(0);
+            bit_widths[k] = (64 - max.leading_zeros()) as u8;
+        }
+        buffer.extend_from_slice(&bit_widths);
+
+        for (k, bit_width) in bit_widths.iter().enumerate() {
+            let start = k * DELTA_VALUES_PER_MINIBLOCK;
+            let miniblock = &residuals[start..start + DELTA_VALUES_PER_MINIBLOCK];
+            bitpack(buffer, miniblock, *bit_width as u32);
+        }
+    }
+}
+
+#[cfg(test)]
+mod delta_binary_packed_tests {
+    use super::*;
+
+    fn zigzag_decode(v: u64) -> i64 {
+        ((v >> 1) as i64) ^ -((v & 1) as i64)
+    }
+
+    fn read_uleb128(buf: &[u8], pos: &mut usize) -> u64 {
+        let mut result = 0u64;
+        let mut shift = 0u32;
+        loop {
+            let byte = buf[*pos];
+            *pos += 1;
+            result |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        result
+    }
+
+    fn unpack(buf: &[u8], pos: &mut usize, width: u32, count: usize) -> Vec<u64> {
+        let mut values = Vec::with_capacity(count);
+        if width == 0 {
+            values.resize(count, 0);
+            return values;
+        }
+        let mask: u128 = (1u128 << width) - 1;
+        let mut acc: u128 = 0;
+        let mut acc_bits = 0u32;
+        for _ in 0..count {
+            while acc_bits < width {
+                acc |= (buf[*pos] as u128) << acc_bits;
+                *pos += 1;
+                acc_bits += 8;
+            }
+            values.push((acc & mask) as u64);
+            acc >>= width;
+            acc_bits -= width;
+        }
+        values
+    }
+
+    fn decode_delta_binary_packed(buf: &[u8]) -> Vec<i64> {
+        let mut pos = 0;
+        let block_size = read_uleb128(buf, &mut pos) as usize;
+        let miniblocks_per_block = read_uleb128(buf, &mut pos) as usize;
+        let total_count = read_uleb128(buf, &mut pos) as usize;
+        let first_value = zigzag_decode(read_uleb128(buf, &mut pos));
+
+        let mut out = Vec::with_capacity(total_count);
+        out.push(first_value);
+        if total_count < 2 {
+            return out;
+        }
+
+        let values_per_miniblock = block_size / miniblocks_per_block;
+        let mut prev = first_value;
+        while out.len() < total_count {
+            let min_delta = zigzag_decode(read_uleb128(buf, &mut pos));
+            let bit_widths = buf[pos..pos + miniblocks_per_block].to_vec();
+            pos += miniblocks_per_block;
+            for bit_width in bit_widths {
+                let residuals = unpack(buf, &mut pos, bit_width as u32, values_per_miniblock);
+                for residual in residuals {
+                    if out.len() >= total_count {
+                        continue;
+                    }
+                    let delta = min_delta.wrapping_add(residual as i64);
+                    prev = prev.wrapping_add(delta);
+                    out.push(prev);
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn delta_binary_packed_round_trips_values() {
+        let values = vec![
+            100i64, 101, 105, 90, i64::MAX, i64::MIN, i64::MIN + 1, 0, -5, -5,
+        ];
+        let mut buffer = vec![];
+        delta_binary_packed_encode(&values, &mut buffer);
+        assert_eq!(decode_delta_binary_packed(&buffer), values);
+    }
+
+    #[test]
+    fn delta_binary_packed_handles_single_value() {
+        let values = vec![42i64];
+        let mut buffer = vec![];
+        delta_binary_packed_encode(&values, &mut buffer);
+        assert_eq!(decode_delta_binary_packed(&buffer), values);
+    }
+
+    #[test]
+    fn delta_binary_packed_round_trips_multiple_blocks() {
+        let values: Vec<i64> = (0..300).map(|i| (i * 37 - 1000) as i64).collect();
+        let mut buffer = vec![];
+        delta_binary_packed_encode(&values, &mut buffer);
+        assert_eq!(decode_delta_binary_packed(&buffer), values);
+    }
+}
+
+/// Delta-bitpacked alternative to `bytes_to_page` for fixed-width columns
+/// whose bytes are really an integer payload — packed timestamps, sequence
+/// IDs — where values tend to be monotonic or tightly clustered and a plain
+/// page would waste space repeating their high bytes.
+pub fn i64_bytes_to_delta_page(
+    data: &[[u8; 8]],
+    validity: Option<&[bool]>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+) -> ParquetResult<Page> {
+    let mut buffer = vec![];
+    let mut null_count = 0;
+
+    let nulls_iterator = data.iter().enumerate().map(|(i, _)| match validity {
+        Some(validity) if !validity[i] => {
+            null_count += 1;
+            false
+        }
+        _ => true,
+    });
+
+    let length = nulls_iterator.len();
+    encode_bool_iter(&mut buffer, nulls_iterator, options.version)?;
+    let definition_levels_byte_length = buffer.len();
+
+    // QuestDB stores the 8-byte Long payload little-endian; decoding it as
+    // big-endian would scramble the value order and defeat delta coding.
+    let values: Vec<i64> = data
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !matches!(validity, Some(validity) if !validity[*i]))
+        .map(|(_, bytes)| i64::from_le_bytes(*bytes))
+        .collect();
+    delta_binary_packed_encode(&values, &mut buffer);
+
+    // DELTA_BINARY_PACKED is only a valid encoding for the INT32/INT64
+    // physical types, not FIXED_LEN_BYTE_ARRAY; write this page under an
+    // INT64 type so conforming readers (arrow/parquet-mr) accept it.
+    let int64_type = PrimitiveType::from_physical(type_.field_info.name.clone(), PhysicalType::Int64);
+
+    let stats = options.statistics;
+    let statistics = if stats.min || stats.max || stats.null_count || stats.distinct_count {
+        Some(build_i64_statistics(&values, null_count, stats, int64_type.clone()))
+    } else {
+        None
+    };
+
+    build_plain_page(
+        buffer,
+        length,
+        length,
+        null_count,
+        definition_levels_byte_length,
+        statistics,
+        int64_type,
+        options,
+        Encoding::DeltaBinaryPacked,
+    )
+        .map(Page::Data)
+}
+
+/// Builds the `[dict page, data page]` pair for a dictionary-encoded,
+/// fixed-size binary column. Row indices are RLE/bit-packed into the data
+/// page via `Encoding::RleDictionary`; good fit for low-cardinality columns
+/// such as UUIDs or Long256 values drawn from a small set.
+fn bytes_to_dict_pages<const N: usize>(
+    data: &[[u8; N]],
+    validity: Option<&[bool]>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+) -> ParquetResult<Vec<Page>> {
+    let (dict_values, indices) = build_dictionary(data, validity);
+
+    let dict_buffer: Vec<u8> = dict_values.iter().flat_map(|v| v.iter().copied()).collect();
+    let dict_page = DictPage::new(dict_buffer, dict_values.len(), false);
+
+    let mut buffer = vec![];
+    let mut null_count = 0;
+
+    let nulls_iterator = data.iter().enumerate().map(|(i, _)| match validity {
+        Some(validity) if !validity[i] => {
+            null_count += 1;
+            false
+        }
+        _ => true,
+    });
 
-fn encode_plain<const N: usize>(data: &[[u8; N]], buffer: &mut Vec<u8>) {
-    // append the non-null values
-    data.iter().for_each(|x| {
-        //TODO: if not a null
-        buffer.extend_from_slice(x);
-    })
+    let length = nulls_iterator.len();
+    encode_bool_iter(&mut buffer, nulls_iterator, options.version)?;
+    let definition_levels_byte_length = buffer.len();
+
+    let num_bits = num_bits_for_index(dict_values.len());
+    buffer.push(num_bits as u8);
+    encode_u32(&mut buffer, indices.iter().copied(), num_bits as usize)?;
+
+    let stats = options.statistics;
+    let statistics = if stats.min || stats.max || stats.null_count || stats.distinct_count {
+        Some(build_statistics(data, validity, null_count, stats))
+    } else {
+        None
+    };
+
+    let data_page = build_plain_page(
+        buffer,
+        length,
+        length,
+        null_count,
+        definition_levels_byte_length,
+        statistics,
+        type_,
+        options,
+        Encoding::RleDictionary,
+    )
+        .map(Page::Data)?;
+
+    Ok(vec![Page::Dict(dict_page), data_page])
+}
+
+/// Assigns each non-null row a `u32` index into the deduplicated set of
+/// values. Null rows are skipped entirely rather than indexed: a reader
+/// derives the number of index entries from `num_values - null_count`, so
+/// the index stream must only cover defined rows, matching the data buffer
+/// produced by `encode_plain` for the plain path.
+fn build_dictionary<const N: usize>(
+    data: &[[u8; N]],
+    validity: Option<&[bool]>,
+) -> (Vec<[u8; N]>, Vec<u32>) {
+    let mut dict_values: Vec<[u8; N]> = vec![];
+    let mut dict_lookup: HashMap<[u8; N], u32> = HashMap::new();
+    let mut indices = Vec::with_capacity(data.len());
+
+    for (i, x) in data.iter().enumerate() {
+        if matches!(validity, Some(validity) if !validity[i]) {
+            continue;
+        }
+        let idx = *dict_lookup.entry(*x).or_insert_with(|| {
+            dict_values.push(*x);
+            (dict_values.len() - 1) as u32
+        });
+        indices.push(idx);
+    }
+
+    (dict_values, indices)
+}
+
+// RLE_DICTIONARY readers reject a 0-bit index stream even for a constant
+// (single-value) dictionary, so the width is always clamped to at least 1.
+fn num_bits_for_index(dict_len: usize) -> u32 {
+    let dict_len = dict_len.max(1);
+    (usize::BITS - (dict_len - 1).leading_zeros()).max(1)
+}
+
+#[cfg(test)]
+mod dictionary_tests {
+    use super::*;
+
+    #[test]
+    fn build_dictionary_dedupes_and_skips_null_rows() {
+        let data: Vec<[u8; 4]> =
+            vec![*b"aaaa", *b"bbbb", *b"aaaa", *b"cccc", *b"bbbb"];
+        let validity = [true, true, false, true, true];
+
+        let (dict_values, indices) = build_dictionary(&data, Some(&validity));
+
+        // one null row (index 2) is dropped, not indexed
+        assert_eq!(indices.len(), 4);
+        assert_eq!(dict_values.len(), 3);
+        assert_eq!(dict_values[indices[0] as usize], *b"aaaa");
+        assert_eq!(dict_values[indices[1] as usize], *b"bbbb");
+        assert_eq!(dict_values[indices[2] as usize], *b"cccc");
+        assert_eq!(dict_values[indices[3] as usize], *b"bbbb");
+    }
+
+    #[test]
+    fn build_dictionary_without_validity_indexes_every_row() {
+        let data: Vec<[u8; 2]> = vec![*b"xx", *b"yy", *b"xx"];
+
+        let (dict_values, indices) = build_dictionary(&data, None);
+
+        assert_eq!(indices.len(), 3);
+        assert_eq!(dict_values.len(), 2);
+        assert_eq!(dict_values[indices[0] as usize], *b"xx");
+        assert_eq!(dict_values[indices[2] as usize], *b"xx");
+    }
+
+    #[test]
+    fn num_bits_for_index_never_returns_zero() {
+        assert_eq!(num_bits_for_index(0), 1);
+        assert_eq!(num_bits_for_index(1), 1);
+        assert_eq!(num_bits_for_index(2), 1);
+        assert_eq!(num_bits_for_index(3), 2);
+        assert_eq!(num_bits_for_index(4), 2);
+        assert_eq!(num_bits_for_index(5), 3);
+    }
 }
 
 pub fn bytes_to_page<const N: usize>(
     data: &[[u8; N]],
+    validity: Option<&[bool]>,
     options: WriteOptions,
     type_: PrimitiveType,
 ) -> ParquetResult<Page> {
     let mut buffer = vec![];
     let mut null_count = 0;
 
-    let nulls_iterator = data.iter().map(|bytes| {
-        // TODO: null
-        if false {
+    let nulls_iterator = data.iter().enumerate().map(|(i, _)| match validity {
+        Some(validity) if !validity[i] => {
             null_count += 1;
             false
-        } else {
-            true
         }
+        _ => true,
     });
 
     let length = nulls_iterator.len();
     encode_bool_iter(&mut buffer, nulls_iterator, options.version)?;
     let definition_levels_byte_length = buffer.len();
-    encode_plain(data, &mut buffer);
+    encode_plain(data, validity, &mut buffer);
+
+    let stats = options.statistics;
+    let statistics = if stats.min || stats.max || stats.null_count || stats.distinct_count {
+        Some(build_statistics(data, validity, null_count, stats))
+    } else {
+        None
+    };
+
     build_plain_page(
         buffer,
         length,
         length,
         null_count,
         definition_levels_byte_length,
-        None, // do we really want a binary statistics?
+        statistics,
+        type_,
+        options,
+        Encoding::Plain,
+    )
+        .map(Page::Data)
+}
+
+/// The offsets and list-level validity of a single level of list nesting,
+/// Arrow-style: `offsets[i]..offsets[i + 1]` gives the range of leaf values
+/// belonging to list `i`, and `validity[i] == false` marks a null list (as
+/// opposed to a present-but-empty one, which has `offsets[i] == offsets[i + 1]`).
+pub struct Nested<'a> {
+    pub offsets: &'a [i64],
+    pub validity: Option<&'a [bool]>,
+}
+
+// Definition levels for a nullable list of nullable leaves, per the
+// Parquet Dremel model (max level 3): 0 = null list, 1 = empty list,
+// 2 = null value, 3 = present value.
+const DEF_LIST_NULL: u32 = 0;
+const DEF_LIST_EMPTY: u32 = 1;
+const DEF_VALUE_NULL: u32 = 2;
+const DEF_VALUE_PRESENT: u32 = 3;
+
+/// The result of walking a `Nested` descriptor: the Dremel repetition and
+/// definition levels for every leaf slot (including null/empty lists), the
+/// present (non-null) leaf values in order, and the total null count across
+/// both null lists and null values.
+struct NestedLevels<const N: usize> {
+    rep_levels: Vec<u32>,
+    def_levels: Vec<u32>,
+    present: Vec<[u8; N]>,
+    null_count: usize,
+}
+
+/// Builds the Dremel repetition/definition levels for `data` nested one
+/// level inside the lists described by `nested`. Pulled out of
+/// `bytes_to_page_nested` so the level construction can be unit-tested
+/// without needing a `WriteOptions`/`PrimitiveType` to drive the page
+/// builder.
+fn build_nested_levels<const N: usize>(
+    data: &[[u8; N]],
+    leaf_validity: Option<&[bool]>,
+    nested: &Nested,
+) -> NestedLevels<N> {
+    let mut rep_levels = vec![];
+    let mut def_levels = vec![];
+    let mut present = vec![];
+    let mut null_count = 0;
+
+    for (i, w) in nested.offsets.windows(2).enumerate() {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        let list_is_null = matches!(nested.validity, Some(v) if !v[i]);
+
+        if list_is_null {
+            null_count += 1;
+            rep_levels.push(0);
+            def_levels.push(DEF_LIST_NULL);
+            continue;
+        }
+        if start == end {
+            rep_levels.push(0);
+            def_levels.push(DEF_LIST_EMPTY);
+            continue;
+        }
+        for (j, idx) in (start..end).enumerate() {
+            let value_is_null = matches!(leaf_validity, Some(v) if !v[idx]);
+            rep_levels.push(if j == 0 { 0 } else { 1 });
+            if value_is_null {
+                null_count += 1;
+                def_levels.push(DEF_VALUE_NULL);
+            } else {
+                def_levels.push(DEF_VALUE_PRESENT);
+                present.push(data[idx]);
+            }
+        }
+    }
+
+    NestedLevels { rep_levels, def_levels, present, null_count }
+}
+
+/// Writes a fixed-size binary column nested one level deep inside a list,
+/// following Parquet's Dremel encoding: a repetition level marks whether a
+/// leaf starts a new list or continues the current one, and a definition
+/// level distinguishes null/empty lists from null/present values so that a
+/// list column round-trips through columnar storage without flattening it.
+pub fn bytes_to_page_nested<const N: usize>(
+    data: &[[u8; N]],
+    leaf_validity: Option<&[bool]>,
+    nested: Nested,
+    options: WriteOptions,
+    type_: PrimitiveType,
+) -> ParquetResult<Page> {
+    let NestedLevels { rep_levels, def_levels, present, null_count } =
+        build_nested_levels(data, leaf_validity, &nested);
+
+    // Parquet V2 data pages frame repetition and definition levels as two
+    // separately-lengthed runs ahead of the values, so a reader can tell
+    // where one ends and the other begins; unlike the flat path, a single
+    // combined byte length isn't enough to describe a repeated column.
+    let num_values = rep_levels.len();
+    let num_rows = nested.offsets.len() - 1;
+
+    let mut buffer = vec![];
+    encode_u32(&mut buffer, rep_levels.iter().copied(), num_bits_for_index(2) as usize)?;
+    let repetition_levels_byte_length = buffer.len();
+    encode_u32(
+        &mut buffer,
+        def_levels.iter().copied(),
+        num_bits_for_index(DEF_VALUE_PRESENT as usize + 1) as usize,
+    )?;
+    let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
+    encode_plain(&present, None, &mut buffer);
+
+    let stats = options.statistics;
+    let statistics = if stats.min || stats.max || stats.null_count || stats.distinct_count {
+        Some(build_statistics(&present, None, null_count, stats))
+    } else {
+        None
+    };
+
+    build_plain_page_nested(
+        buffer,
+        num_values,
+        num_rows,
+        null_count,
+        repetition_levels_byte_length,
+        definition_levels_byte_length,
+        statistics,
         type_,
         options,
         Encoding::Plain,
     )
         .map(Page::Data)
+}
+
+#[cfg(test)]
+mod nested_levels_tests {
+    use super::*;
+
+    #[test]
+    fn marks_null_empty_and_partially_null_lists() {
+        // Row 0: a null list. Row 1: a present-but-empty list.
+        // Row 2: a two-value list whose second value is null.
+        let offsets = [0i64, 0, 0, 2];
+        let list_validity = [false, true, true];
+        let leaf_validity = [true, false];
+        let data: Vec<[u8; 1]> = vec![[1], [2]];
+
+        let nested = Nested { offsets: &offsets, validity: Some(&list_validity) };
+        let levels = build_nested_levels(&data, Some(&leaf_validity), &nested);
+
+        assert_eq!(levels.rep_levels, vec![0, 0, 0, 1]);
+        assert_eq!(
+            levels.def_levels,
+            vec![DEF_LIST_NULL, DEF_LIST_EMPTY, DEF_VALUE_PRESENT, DEF_VALUE_NULL]
+        );
+        assert_eq!(levels.present, vec![[1u8]]);
+        assert_eq!(levels.null_count, 2);
+    }
+
+    #[test]
+    fn continuation_values_get_repetition_level_one() {
+        // A single row with three present values: only the first leaf
+        // starts the list (rep level 0), the rest continue it (rep level 1).
+        let offsets = [0i64, 3];
+        let data: Vec<[u8; 1]> = vec![[1], [2], [3]];
+
+        let nested = Nested { offsets: &offsets, validity: None };
+        let levels = build_nested_levels(&data, None, &nested);
+
+        assert_eq!(levels.rep_levels, vec![0, 1, 1]);
+        assert_eq!(levels.def_levels, vec![DEF_VALUE_PRESENT; 3]);
+        assert_eq!(levels.present, data);
+        assert_eq!(levels.null_count, 0);
+    }
 }
\ No newline at end of file